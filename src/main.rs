@@ -24,12 +24,173 @@ enum Commands {
         /// Sets the key in the JSON file
         #[arg(short = 'k', long)]
         key: String,
+
+        #[command(flatten)]
+        opts: CompareOptions,
     },
     /// Use for plain version file
     Plain {
         /// Sets the plain text file
         #[arg(short = 'f', long)]
         file: String,
+
+        #[command(flatten)]
+        opts: CompareOptions,
+    },
+    /// Use for TOML version file
+    Toml {
+        /// Sets the TOML file
+        #[arg(short = 'f', long)]
+        file: String,
+
+        /// Sets the dotted key path in the TOML file (e.g. package.version)
+        #[arg(short = 'k', long)]
+        key: String,
+
+        #[command(flatten)]
+        opts: CompareOptions,
+    },
+    /// Use for YAML version file
+    Yaml {
+        /// Sets the YAML file
+        #[arg(short = 'f', long)]
+        file: String,
+
+        /// Sets the dotted key path in the YAML file (e.g. appVersion)
+        #[arg(short = 'k', long)]
+        key: String,
+
+        #[command(flatten)]
+        opts: CompareOptions,
+    }
+}
+
+/// Options shared by every version-file subcommand.
+#[derive(clap::Args)]
+struct CompareOptions {
+    /// Fail unless the detected bump matches this level
+    #[arg(long)]
+    require: Option<BumpLevel>,
+
+    /// Compare against this revspec (branch, tag, or SHA) instead of HEAD~1
+    #[arg(long, conflicts_with = "against_latest_tag")]
+    base: Option<String>,
+
+    /// Compare against the highest-numbered parsed semver git tag instead of HEAD~1
+    #[arg(long)]
+    against_latest_tag: bool,
+
+    /// Fail unless the new version satisfies this requirement (e.g. ">=1.2, <2.0")
+    #[arg(long)]
+    satisfies: Option<semver::VersionReq>,
+
+    /// Allow the current version to be a pre-release (alpha/beta/rc), ordered by channel
+    #[arg(long)]
+    allow_prerelease: bool,
+}
+
+/// Release channel of a pre-release identifier, ordered `Alpha < Beta < Rc < Final`.
+/// An empty `Version::pre` (i.e. a final release) is always `Final`, the
+/// highest channel for a given major.minor.patch triple.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ReleaseChannel {
+    Alpha,
+    Beta,
+    Rc,
+    Final,
+}
+
+impl ReleaseChannel {
+    // Parse the channel from the leading identifier of a pre-release string, e.g. the
+    // "alpha" in "alpha.1" or the "rc" in "rc1". Anything we don't recognize is an error
+    // rather than a silent guess, since defaulting an unknown channel would let us call a
+    // regression (e.g. "dev" after "beta") a valid forward progression.
+    fn from_pre(pre: &semver::Prerelease) -> Result<ReleaseChannel, String> {
+        if pre.is_empty() {
+            return Ok(ReleaseChannel::Final);
+        }
+        let first = pre.as_str().split('.').next().unwrap_or("");
+        let letters: String = first.chars().take_while(|c| c.is_ascii_alphabetic()).collect();
+        match letters.to_ascii_lowercase().as_str() {
+            "alpha" | "a" => Ok(ReleaseChannel::Alpha),
+            "beta" | "b" => Ok(ReleaseChannel::Beta),
+            "rc" => Ok(ReleaseChannel::Rc),
+            _ => Err(format!("unrecognized pre-release channel `{}` in `{}` (expected alpha/beta/rc)", letters, pre)),
+        }
+    }
+}
+
+// Numeric counter that follows the channel identifier, e.g. the `1` in "alpha.1" or "rc1".
+fn prerelease_counter(pre: &semver::Prerelease) -> u64 {
+    let s = pre.as_str();
+    if let Some(counter) = s.split('.').nth(1).and_then(|n| n.parse().ok()) {
+        return counter;
+    }
+    let first = s.split('.').next().unwrap_or("");
+    let digits: String = first.chars().skip_while(|c| c.is_ascii_alphabetic()).collect();
+    digits.parse().unwrap_or(0)
+}
+
+// Compare two versions, ordering same-triple pre-releases by release channel
+// and then by their numeric counter instead of the raw lexical `Version` order.
+fn compare_with_channels(old: &Version, new: &Version) -> Result<std::cmp::Ordering, String> {
+    let triple_cmp = (old.major, old.minor, old.patch).cmp(&(new.major, new.minor, new.patch));
+    if triple_cmp != std::cmp::Ordering::Equal {
+        return Ok(triple_cmp);
+    }
+    let old_channel = ReleaseChannel::from_pre(&old.pre)?;
+    let new_channel = ReleaseChannel::from_pre(&new.pre)?;
+    Ok(match old_channel.cmp(&new_channel) {
+        std::cmp::Ordering::Equal => prerelease_counter(&old.pre).cmp(&prerelease_counter(&new.pre)),
+        other => other,
+    })
+}
+
+/// The kind of change between two versions, following semver precedence.
+///
+/// Note the 0.x edge case: on a pre-1.0 major line, semver treats a minor
+/// bump as a potentially breaking change, but `classify_bump` only reports
+/// the raw component that changed — callers that care about the 0.x
+/// convention should special-case `Minor` themselves when `major == 0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum BumpLevel {
+    Major,
+    Minor,
+    Patch,
+    PreRelease,
+    Build,
+}
+
+impl std::fmt::Display for BumpLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            BumpLevel::Major => "major",
+            BumpLevel::Minor => "minor",
+            BumpLevel::Patch => "patch",
+            BumpLevel::PreRelease => "prerelease",
+            BumpLevel::Build => "build",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+// Determine which component changed between two versions, in semver precedence order.
+// Assumes `new` is a forward bump from `old` (callers already enforce this via
+// `compare_with_channels`); major/minor equality is still checked explicitly per
+// component, so a backwards change in a higher component is never reported as a
+// lower-level bump. A pair that is not a forward bump falls through to
+// `PreRelease` or `Build`.
+fn classify_bump(old: &Version, new: &Version) -> BumpLevel {
+    if new.major > old.major {
+        BumpLevel::Major
+    } else if new.major == old.major && new.minor > old.minor {
+        BumpLevel::Minor
+    } else if new.major == old.major && new.minor == old.minor && new.patch > old.patch {
+        BumpLevel::Patch
+    } else if new.pre != old.pre {
+        BumpLevel::PreRelease
+    } else {
+        BumpLevel::Build
     }
 }
 
@@ -40,12 +201,20 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     match &cli.command {
         // Handling JSON version file subcommand
-        Some(Commands::Json { file, key }) => {
-            compare_versions::<JsonVersionFile>(&repo, file, key)?;
+        Some(Commands::Json { file, key, opts }) => {
+            compare_versions(&repo, file, key, opts)?;
         }
         // Handling plain version file subcommand
-        Some(Commands::Plain { file }) => {
-            compare_versions::<TextVersionFile>(&repo, file, "")?;
+        Some(Commands::Plain { file, opts }) => {
+            compare_versions(&repo, file, "", opts)?;
+        }
+        // Handling TOML version file subcommand
+        Some(Commands::Toml { file, key, opts }) => {
+            compare_versions(&repo, file, key, opts)?;
+        }
+        // Handling YAML version file subcommand
+        Some(Commands::Yaml { file, key, opts }) => {
+            compare_versions(&repo, file, key, opts)?;
         }
         // No subcommand provided
         None => {}
@@ -55,7 +224,7 @@ fn main() -> Result<(), Box<dyn Error>> {
 
 // Function to check if a version string adheres to semver format
 fn is_semver_version(version_str: &str) -> Result<(), &'static str> {
-    if !semver::Version::parse(version_str).is_ok() {
+    if semver::Version::parse(version_str).is_err() {
         return Err("Version does not adhere to semver 🙈");
     }
     Ok(())
@@ -72,7 +241,46 @@ struct JsonVersionFile;
 impl VersionFile for JsonVersionFile {
     fn get_version(content: &[u8], key: &str) -> Result<Version, Box<dyn Error>> {
         let data: serde_json::Value = serde_json::from_slice(content)?;
-        let version_str = data[key].as_str().ok_or("Version not found")?;
+        let mut current = &data;
+        for part in key.split('.') {
+            current = current.get(part).ok_or("Version key not found")?;
+        }
+        let version_str = current.as_str().ok_or("Version not found")?;
+        is_semver_version(version_str)?;
+        let version = Version::parse(version_str)?;
+        Ok(version)
+    }
+}
+
+// Implementation for TOML version file
+struct TomlVersionFile;
+
+impl VersionFile for TomlVersionFile {
+    fn get_version(content: &[u8], key: &str) -> Result<Version, Box<dyn Error>> {
+        let content_str = std::str::from_utf8(content)?;
+        let data: toml::Value = toml::from_str(content_str)?;
+        let mut current = &data;
+        for part in key.split('.') {
+            current = current.get(part).ok_or("Version key not found")?;
+        }
+        let version_str = current.as_str().ok_or("Version not found")?;
+        is_semver_version(version_str)?;
+        let version = Version::parse(version_str)?;
+        Ok(version)
+    }
+}
+
+// Implementation for YAML version file
+struct YamlVersionFile;
+
+impl VersionFile for YamlVersionFile {
+    fn get_version(content: &[u8], key: &str) -> Result<Version, Box<dyn Error>> {
+        let data: serde_yaml::Value = serde_yaml::from_slice(content)?;
+        let mut current = &data;
+        for part in key.split('.') {
+            current = current.get(part).ok_or("Version key not found")?;
+        }
+        let version_str = current.as_str().ok_or("Version not found")?;
         is_semver_version(version_str)?;
         let version = Version::parse(version_str)?;
         Ok(version)
@@ -100,13 +308,13 @@ fn get_current_version_from_file<F: VersionFile>(file: &str, key: &str) -> Resul
 
 // Function to read version from content
 fn get_current_version_from_content<F: VersionFile>(content: &[u8], key: &str) -> Result<Version, Box<dyn Error>> {
-    let version = F::get_version(&content, key)?;
+    let version = F::get_version(content, key)?;
     Ok(version)
 }
 
 // Function to determine file type based on extension
 fn determine_file_type(file_path: &str) -> Option<String> {
-    if let Some(extension) = file_path.split('.').last() {
+    if let Some(extension) = file_path.split('.').next_back() {
         match extension {
             "json" => Some("json".to_string()),
             "yaml" => Some("yaml".to_string()),
@@ -118,28 +326,22 @@ fn determine_file_type(file_path: &str) -> Option<String> {
     }
 }
 
-// Function to get version from previous commit
-fn get_version_from_previous_commit<F: VersionFile>(repo: &Repository, file: &str, key: &str) -> Result<Version, Box<dyn Error>> {
-
-    let head = repo.head()?.peel_to_commit()?;
-    let previous_commit = head.parent(0)?; // Get the first parent (previous commit)
-    let tree = previous_commit.tree()?;
+// Function to read the version file out of a given commit's tree
+fn get_version_from_commit(repo: &Repository, commit: &git2::Commit, file: &str, key: &str) -> Result<Version, Box<dyn Error>> {
+    let tree = commit.tree()?;
     let file_name = tree.get_name(file)
-        .ok_or("File not found in previous commit")?;
-    let object = file_name.to_object(&repo)?;
+        .ok_or("File not found in base commit")?;
+    let object = file_name.to_object(repo)?;
     let blob = object.peel(ObjectType::Blob)?;
     let content = blob.as_blob().ok_or("Not a blob")?.content();
 
     let file_type = determine_file_type(file);
 
-    let version = match file_type {
-        Some(file_type) => {
-            if file_type == "json" {
-                get_current_version_from_content::<JsonVersionFile>(content, key)?
-            } else {
-                get_current_version_from_content::<TextVersionFile>(content, key)?
-            }
-        }
+    let version = match file_type.as_deref() {
+        Some("json") => get_current_version_from_content::<JsonVersionFile>(content, key)?,
+        Some("toml") => get_current_version_from_content::<TomlVersionFile>(content, key)?,
+        Some("yaml") => get_current_version_from_content::<YamlVersionFile>(content, key)?,
+        None => get_current_version_from_content::<TextVersionFile>(content, key)?,
         _ => {
             return Err("Unknown file type".into());
         }
@@ -148,26 +350,264 @@ fn get_version_from_previous_commit<F: VersionFile>(repo: &Repository, file: &st
     Ok(version)
 }
 
+// Resolve the commit to compare against: HEAD~1 by default, an explicit
+// `--base` revspec, or the commit tagged with the highest parsed semver tag.
+fn resolve_base_commit<'repo>(repo: &'repo Repository, base: &Option<String>, against_latest_tag: bool) -> Result<git2::Commit<'repo>, Box<dyn Error>> {
+    if against_latest_tag {
+        find_latest_semver_tag_commit(repo)
+    } else if let Some(base_ref) = base {
+        let object = repo.revparse_single(base_ref)?;
+        let commit = object.peel_to_commit()?;
+        Ok(commit)
+    } else {
+        let head = repo.head()?.peel_to_commit()?;
+        let previous_commit = head.parent(0)?; // Get the first parent (previous commit)
+        Ok(previous_commit)
+    }
+}
+
+// Parse a tag name as a semver version, stripping a leading `v` if present.
+// Returns None for tags that aren't semver (e.g. "latest", "release-candidate").
+fn parse_tag_as_semver(name: &str) -> Option<Version> {
+    let version_str = name.strip_prefix('v').unwrap_or(name);
+    Version::parse(version_str).ok()
+}
+
+// Enumerate all tags, parse each as semver (optionally stripping a leading
+// `v`), and return the commit tagged with the highest one.
+fn find_latest_semver_tag_commit<'repo>(repo: &'repo Repository) -> Result<git2::Commit<'repo>, Box<dyn Error>> {
+    let tag_names = repo.tag_names(None)?;
+    let mut best: Option<(Version, git2::Commit)> = None;
+
+    for name in tag_names.iter().flatten() {
+        let version = match parse_tag_as_semver(name) {
+            Some(version) => version,
+            None => continue, // Ignore tags that aren't semver
+        };
+
+        let is_better = match &best {
+            Some((best_version, _)) => version > *best_version,
+            None => true,
+        };
+        if is_better {
+            let commit = repo.revparse_single(name)?.peel_to_commit()?;
+            best = Some((version, commit));
+        }
+    }
+
+    let (_, commit) = best.ok_or("No semver git tags found")?;
+    Ok(commit)
+}
+
 // Function to compare current and previous versions
-fn compare_versions<F: VersionFile>(repo: &Repository, file: &str, key: &str) -> Result<(), Box<dyn std::error::Error>> {
+fn compare_versions(repo: &Repository, file: &str, key: &str, opts: &CompareOptions) -> Result<(), Box<dyn std::error::Error>> {
     let file_type = determine_file_type(file);
-    let current_version = match file_type {
-        Some(file_type) => {
-            if file_type == "json" {
-                get_current_version_from_file::<JsonVersionFile>(file, key)?
-            } else {
-                get_current_version_from_file::<TextVersionFile>(file, key)?
-            }
-        }
+    let current_version = match file_type.as_deref() {
+        Some("json") => get_current_version_from_file::<JsonVersionFile>(file, key)?,
+        Some("toml") => get_current_version_from_file::<TomlVersionFile>(file, key)?,
+        Some("yaml") => get_current_version_from_file::<YamlVersionFile>(file, key)?,
+        None => get_current_version_from_file::<TextVersionFile>(file, key)?,
         _ => {
             return Err("Unknown file type".into());
         }
     };
 
-    let previous_commit_version = get_version_from_previous_commit::<F>(repo, file, key)?;
-    if previous_commit_version >= current_version {
+    if !opts.allow_prerelease && !current_version.pre.is_empty() {
+        return Err(format!("Current version ({}) is a pre-release; pass --allow-prerelease to allow staged releases 🦆", current_version).into());
+    }
+
+    let base_commit = resolve_base_commit(repo, &opts.base, opts.against_latest_tag)?;
+    let previous_commit_version = get_version_from_commit(repo, &base_commit, file, key)?;
+    if compare_with_channels(&previous_commit_version, &current_version)? != std::cmp::Ordering::Less {
         return Err(format!("Current version ({}) is not greater than previous version ({}) 🦆", current_version, previous_commit_version).into());
     }
+
+    let bump = classify_bump(&previous_commit_version, &current_version);
+    println!("{} bump {} → {}", bump, previous_commit_version, current_version);
+
+    if let Some(required) = opts.require {
+        if bump != required {
+            return Err(format!("Expected a {} bump but found a {} bump ({} → {}) 🦆", required, bump, previous_commit_version, current_version).into());
+        }
+    }
+
+    if let Some(req) = &opts.satisfies {
+        if !req.matches(&current_version) {
+            return Err(format!("Version {} does not satisfy requirement `{}` 🦆", current_version, req).into());
+        }
+    }
+
     println!("Current version is greater than the previous one 🚀🚀🚀");
     Ok(())
 }
+
+#[cfg(test)]
+mod satisfies_tests {
+    use super::*;
+
+    fn v(s: &str) -> Version {
+        Version::parse(s).unwrap()
+    }
+
+    fn req(s: &str) -> semver::VersionReq {
+        semver::VersionReq::parse(s).unwrap()
+    }
+
+    #[test]
+    fn version_within_range_satisfies_requirement() {
+        assert!(req(">=1.2, <2.0").matches(&v("1.5.0")));
+    }
+
+    #[test]
+    fn version_outside_range_does_not_satisfy_requirement() {
+        assert!(!req(">=1.2, <2.0").matches(&v("2.5.0")));
+    }
+
+    #[test]
+    fn lower_and_upper_bounds_are_respected() {
+        let r = req(">=1.2, <2.0");
+        assert!(r.matches(&v("1.2.0")));
+        assert!(!r.matches(&v("2.0.0")));
+        assert!(!r.matches(&v("1.1.9")));
+    }
+
+    #[test]
+    fn error_message_reports_version_and_requirement() {
+        let current_version = v("2.5.0");
+        let r = req(">=1.2, <2.0");
+        let message = format!("Version {} does not satisfy requirement `{}` 🦆", current_version, r);
+        assert_eq!(message, "Version 2.5.0 does not satisfy requirement `>=1.2, <2.0` 🦆");
+    }
+}
+
+#[cfg(test)]
+mod release_channel_tests {
+    use super::*;
+    use std::cmp::Ordering;
+
+    fn v(s: &str) -> Version {
+        Version::parse(s).unwrap()
+    }
+
+    #[test]
+    fn final_outranks_every_prerelease_channel() {
+        assert_eq!(ReleaseChannel::from_pre(&v("1.0.0").pre).unwrap(), ReleaseChannel::Final);
+        assert!(ReleaseChannel::Final > ReleaseChannel::Rc);
+    }
+
+    #[test]
+    fn recognizes_rc_without_a_dot_separator() {
+        assert_eq!(ReleaseChannel::from_pre(&v("1.2.3-rc1").pre).unwrap(), ReleaseChannel::Rc);
+    }
+
+    #[test]
+    fn rejects_unknown_channels_instead_of_defaulting() {
+        assert!(ReleaseChannel::from_pre(&v("1.2.3-dev.1").pre).is_err());
+        assert!(ReleaseChannel::from_pre(&v("1.2.3-nightly").pre).is_err());
+    }
+
+    #[test]
+    fn staged_progression_is_ordered_alpha_beta_rc_final() {
+        assert_eq!(compare_with_channels(&v("1.4.0-alpha.1"), &v("1.4.0-beta.1")).unwrap(), Ordering::Less);
+        assert_eq!(compare_with_channels(&v("1.4.0-beta.1"), &v("1.4.0-rc.1")).unwrap(), Ordering::Less);
+        assert_eq!(compare_with_channels(&v("1.4.0-rc.1"), &v("1.4.0")).unwrap(), Ordering::Less);
+    }
+
+    #[test]
+    fn rejects_channel_regression() {
+        assert_eq!(compare_with_channels(&v("1.4.0-beta.1"), &v("1.4.0-alpha.1")).unwrap(), Ordering::Greater);
+        assert_eq!(compare_with_channels(&v("1.4.0"), &v("1.4.0-alpha.1")).unwrap(), Ordering::Greater);
+    }
+
+    #[test]
+    fn rejects_unrecognized_channel_without_silently_ordering_it() {
+        assert!(compare_with_channels(&v("1.4.0-rc1"), &v("1.4.0-nightly.1")).is_err());
+    }
+}
+
+#[cfg(test)]
+mod tag_parsing_tests {
+    use super::*;
+
+    #[test]
+    fn parses_bare_semver_tag() {
+        assert_eq!(parse_tag_as_semver("1.2.3"), Some(Version::parse("1.2.3").unwrap()));
+    }
+
+    #[test]
+    fn strips_leading_v() {
+        assert_eq!(parse_tag_as_semver("v1.2.3"), Some(Version::parse("1.2.3").unwrap()));
+    }
+
+    #[test]
+    fn rejects_non_semver_tags() {
+        assert_eq!(parse_tag_as_semver("latest"), None);
+        assert_eq!(parse_tag_as_semver("release-candidate"), None);
+    }
+}
+
+#[cfg(test)]
+mod classify_bump_tests {
+    use super::*;
+
+    fn v(s: &str) -> Version {
+        Version::parse(s).unwrap()
+    }
+
+    #[test]
+    fn detects_major_bump() {
+        assert_eq!(classify_bump(&v("1.2.3"), &v("2.0.0")), BumpLevel::Major);
+    }
+
+    #[test]
+    fn detects_minor_bump() {
+        assert_eq!(classify_bump(&v("1.2.3"), &v("1.3.0")), BumpLevel::Minor);
+    }
+
+    #[test]
+    fn detects_patch_bump() {
+        assert_eq!(classify_bump(&v("1.2.3"), &v("1.2.4")), BumpLevel::Patch);
+    }
+
+    #[test]
+    fn detects_prerelease_change_at_equal_triple() {
+        assert_eq!(classify_bump(&v("1.2.3-alpha.1"), &v("1.2.3-beta.1")), BumpLevel::PreRelease);
+    }
+
+    #[test]
+    fn does_not_misreport_a_backwards_major_as_minor() {
+        // old.major > new.major: this isn't a forward bump at all, so it must
+        // not be classified as Minor just because new.minor > old.minor.
+        assert_eq!(classify_bump(&v("3.0.0"), &v("2.9.5")), BumpLevel::Build);
+    }
+}
+
+#[cfg(test)]
+mod dotted_path_tests {
+    use super::*;
+
+    #[test]
+    fn json_reads_top_level_and_dotted_keys() {
+        let content = br#"{"version": "1.2.3", "package": {"version": "4.5.6"}}"#;
+        assert_eq!(JsonVersionFile::get_version(content, "version").unwrap(), Version::parse("1.2.3").unwrap());
+        assert_eq!(JsonVersionFile::get_version(content, "package.version").unwrap(), Version::parse("4.5.6").unwrap());
+    }
+
+    #[test]
+    fn json_missing_key_is_an_error() {
+        let content = br#"{"version": "1.2.3"}"#;
+        assert!(JsonVersionFile::get_version(content, "package.version").is_err());
+    }
+
+    #[test]
+    fn toml_reads_dotted_key_path() {
+        let content = b"[package]\nversion = \"1.2.3\"\n";
+        assert_eq!(TomlVersionFile::get_version(content, "package.version").unwrap(), Version::parse("1.2.3").unwrap());
+    }
+
+    #[test]
+    fn yaml_reads_dotted_key_path() {
+        let content = b"nested:\n  appVersion: 1.2.3\n";
+        assert_eq!(YamlVersionFile::get_version(content, "nested.appVersion").unwrap(), Version::parse("1.2.3").unwrap());
+    }
+}